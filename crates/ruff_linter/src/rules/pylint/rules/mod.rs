@@ -0,0 +1,5 @@
+pub(crate) use redundant_dict_reaccess::*;
+pub(crate) use swap_with_temporary_variable::*;
+
+mod redundant_dict_reaccess;
+mod swap_with_temporary_variable;
@@ -0,0 +1,284 @@
+use ruff_diagnostics::{Applicability, Edit, Fix};
+use ruff_python_ast::comparable::ComparableExpr;
+use ruff_python_ast::visitor::{self, Visitor};
+use ruff_python_ast::{CmpOp, Expr, ExprContext, ExprSubscript, Stmt, StmtIf};
+use ruff_text_size::{Ranged, TextRange};
+
+use ruff_macros::{ViolationMetadata, derive_message_formats};
+
+use crate::checkers::ast::Checker;
+use crate::{FixAvailability, Violation};
+
+/// ## What it does
+/// Checks for a dictionary key membership check that's immediately followed
+/// by a lookup of the same key, of the form `if key in d: ... d[key] ...`.
+///
+/// ## Why is this bad?
+/// The membership check already makes a cleaner form available: `d.get(key)`
+/// looks the key up once and doubles as the `None` check, instead of probing
+/// the dictionary twice (once in the `in` check, once in the subscript).
+///
+/// ## Example
+/// ```python
+/// if key in d:
+///     print(d[key])
+/// ```
+///
+/// Use instead:
+/// ```python
+/// if (value := d.get(key)) is not None:
+///     print(value)
+/// ```
+///
+/// ## Fix safety
+/// The fix is only offered when `d[key]` is the sole subscript of `d` in the
+/// guarded block, with no other subscript of `d` beside it (neither a read
+/// under another key nor a write or `del` under any key), when neither `d` nor `key` are reassigned between the guard and the
+/// last reaccess, when the `if` has no `elif`/`else` clause, and when
+/// `value` isn't already bound in the enclosing scope (the walrus target
+/// would otherwise leak out and clobber it). It is suppressed entirely when
+/// `key` or `d` aren't simple references (e.g. `d[f()]`), since re-evaluating
+/// a side-effecting expression fewer times could change behavior.
+#[derive(ViolationMetadata)]
+#[violation_metadata(preview_since = "0.14.12")]
+pub(crate) struct RedundantDictReaccess {
+    key: String,
+    dict: String,
+}
+
+impl Violation for RedundantDictReaccess {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes(
+        "Replace the membership check and subscript with `dict.get`",
+    );
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let RedundantDictReaccess { key, dict } = self;
+        format!(
+            "Use `{dict}.get({key})` instead of reaccessing `{dict}[{key}]` after checking `{key} in {dict}`"
+        )
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Use the walrus operator with `.get`".to_string())
+    }
+}
+
+pub(crate) fn redundant_dict_reaccess(checker: &Checker, stmt_if: &StmtIf) {
+    let Expr::Compare(compare) = &*stmt_if.test else {
+        return;
+    };
+    let ([CmpOp::In], [dict_expr]) = (compare.ops.as_ref(), compare.comparators.as_ref()) else {
+        return;
+    };
+    let key_expr = &*compare.left;
+
+    if !is_safe_to_repeat(dict_expr) || !is_safe_to_repeat(key_expr) {
+        return;
+    }
+
+    let mut collector = DictSubscriptCollector {
+        dict: dict_expr,
+        key: key_expr,
+        matches: Vec::new(),
+        other_subscripts_of_dict: false,
+    };
+    for stmt in &stmt_if.body {
+        collector.visit_stmt(stmt);
+    }
+
+    let [first_match, rest @ ..] = collector.matches.as_slice() else {
+        return;
+    };
+
+    let key = checker.locator().slice(key_expr.range()).to_string();
+    let dict = checker.locator().slice(dict_expr.range()).to_string();
+    let mut diagnostic = checker.report_diagnostic(
+        RedundantDictReaccess {
+            key: key.clone(),
+            dict: dict.clone(),
+        },
+        first_match.range(),
+    );
+
+    // A single `value` can't stand in for more than one distinct subscript of
+    // `d`, nor reflect a write to `d`, so if other subscripts of `d` also
+    // appear in the block (a read under a different key, or a write or `del`
+    // under any key), only report the issue without offering a fix.
+    if collector.other_subscripts_of_dict {
+        return;
+    }
+
+    // The `value := d.get(key)` walrus rewrite only reads cleanly when the
+    // `if` branches solely on membership, i.e. it has no `elif`/`else`.
+    if !stmt_if.elif_else_clauses.is_empty() {
+        return;
+    }
+
+    // If `d` or `key` are rebound anywhere in the guarded block before the
+    // *last* reaccess, the fix would change which dictionary/key is actually
+    // read by at least one of the (now-merged) reaccesses. Checking against
+    // the last match rather than the first also catches a rebind that falls
+    // between two reaccesses, not just one before all of them.
+    let last_match = rest.last().copied().unwrap_or(*first_match);
+    if is_rebound_before(checker, dict_expr, &stmt_if.body, last_match.range())
+        || is_rebound_before(checker, key_expr, &stmt_if.body, last_match.range())
+    {
+        return;
+    }
+
+    // The walrus target leaks into the enclosing scope (an `if` test isn't
+    // its own scope, unlike a comprehension), so introducing `value` is only
+    // safe when that name isn't already bound there; otherwise the fix would
+    // silently shadow or clobber an existing variable.
+    if checker.semantic().current_scope().get("value").is_some() {
+        return;
+    }
+
+    let mut edits = vec![Edit::range_replacement(
+        format!("(value := {dict}.get({key})) is not None"),
+        stmt_if.test.range(),
+    )];
+    for subscript in std::iter::once(*first_match).chain(rest.iter().copied()) {
+        edits.push(Edit::range_replacement("value".to_string(), subscript.range()));
+    }
+    let (first_edit, other_edits) = edits.split_first().unwrap();
+
+    let applicability = if checker.comment_ranges().intersects(stmt_if.range()) {
+        Applicability::Unsafe
+    } else {
+        Applicability::Safe
+    };
+    diagnostic.set_fix(Fix::applicable_edits(
+        first_edit.clone(),
+        other_edits.to_vec(),
+        applicability,
+    ));
+}
+
+/// Returns `true` if `expr` is simple and side-effect free enough to
+/// re-evaluate a different number of times than the original code did
+/// (e.g. a name, attribute/subscript chain, or literal, but not a call).
+fn is_safe_to_repeat(expr: &Expr) -> bool {
+    match expr {
+        Expr::Name(_) => true,
+        Expr::Attribute(attribute) => is_safe_to_repeat(&attribute.value),
+        Expr::Subscript(subscript) => {
+            is_safe_to_repeat(&subscript.value) && is_safe_to_repeat(&subscript.slice)
+        }
+        _ => expr.is_literal_expr(),
+    }
+}
+
+/// Returns `true` if `expr` is rebound inside `body` before `before`,
+/// either because a binding for a name it's rooted in is introduced there
+/// (e.g. `key = ...` for `key`), or because `expr` itself (e.g. `obj.d`) is
+/// the target of a `Store` somewhere in the block. The latter matters for
+/// attribute/subscript expressions: reassigning `obj.d` doesn't rebind the
+/// name `obj`, but it does mean `obj.d` no longer refers to what it did at
+/// the membership check.
+fn is_rebound_before(checker: &Checker, expr: &Expr, body: &[Stmt], before: TextRange) -> bool {
+    let Some(body_range) = body.first().zip(body.last()).map(|(first, last)| {
+        TextRange::new(first.range().start(), last.range().end())
+    }) else {
+        return false;
+    };
+
+    let mut names = Vec::new();
+    collect_names(expr, &mut names);
+
+    let name_rebound = names.iter().any(|name| {
+        checker.semantic().bindings.iter().any(|binding| {
+            body_range.contains_range(binding.range)
+                && binding.range.start() < before.start()
+                && checker.locator().slice(binding.range) == name.as_str()
+        })
+    });
+    if name_rebound {
+        return true;
+    }
+
+    let mut collector = ReassignmentCollector {
+        target: expr,
+        before,
+        found: false,
+    };
+    for stmt in body {
+        collector.visit_stmt(stmt);
+    }
+    collector.found
+}
+
+fn collect_names<'a>(expr: &'a Expr, names: &mut Vec<&'a ruff_python_ast::name::Name>) {
+    match expr {
+        Expr::Name(name) => names.push(&name.id),
+        Expr::Attribute(attribute) => collect_names(&attribute.value, names),
+        Expr::Subscript(subscript) => {
+            collect_names(&subscript.value, names);
+            collect_names(&subscript.slice, names);
+        }
+        _ => {}
+    }
+}
+
+/// Visits a block looking for a `Store`-context write to exactly `target`
+/// (e.g. `obj.d = ...` writing to `obj.d`), recording whether one occurs
+/// before `before`.
+struct ReassignmentCollector<'a> {
+    target: &'a Expr,
+    before: TextRange,
+    found: bool,
+}
+
+impl<'a> Visitor<'a> for ReassignmentCollector<'a> {
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        let ctx = match expr {
+            Expr::Name(name) => Some(name.ctx),
+            Expr::Attribute(attribute) => Some(attribute.ctx),
+            Expr::Subscript(subscript) => Some(subscript.ctx),
+            _ => None,
+        };
+        if ctx == Some(ExprContext::Store)
+            && expr.range().start() < self.before.start()
+            && ComparableExpr::from(expr) == ComparableExpr::from(self.target)
+        {
+            self.found = true;
+        }
+        visitor::walk_expr(self, expr);
+    }
+}
+
+/// Collects subscripts of `dict` within the visited statements, splitting
+/// reads that match `dict[key]` exactly from everything else: differently
+/// keyed reads and any write (e.g. `d[key] = ...`) under any key, all of
+/// which are noted as disqualifying, since the cached `value` wouldn't
+/// reflect them.
+struct DictSubscriptCollector<'a> {
+    dict: &'a Expr,
+    key: &'a Expr,
+    matches: Vec<&'a ExprSubscript>,
+    other_subscripts_of_dict: bool,
+}
+
+impl<'a> Visitor<'a> for DictSubscriptCollector<'a> {
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        if let Expr::Subscript(subscript) = expr {
+            if ComparableExpr::from(&subscript.value) == ComparableExpr::from(self.dict) {
+                match subscript.ctx {
+                    ExprContext::Load
+                        if ComparableExpr::from(&subscript.slice)
+                            == ComparableExpr::from(self.key) =>
+                    {
+                        self.matches.push(subscript);
+                    }
+                    ExprContext::Load => self.other_subscripts_of_dict = true,
+                    ExprContext::Store | ExprContext::Del => {
+                        self.other_subscripts_of_dict = true;
+                    }
+                    ExprContext::Invalid => {}
+                }
+            }
+        }
+        visitor::walk_expr(self, expr);
+    }
+}
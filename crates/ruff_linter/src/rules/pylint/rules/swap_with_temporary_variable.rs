@@ -1,7 +1,7 @@
-use itertools::Itertools;
 use ruff_diagnostics::{Applicability, Edit, Fix};
-use ruff_python_ast::Stmt;
+use ruff_python_ast::comparable::ComparableExpr;
 use ruff_python_ast::name::Name;
+use ruff_python_ast::{Expr, ExprAttribute, ExprSubscript, Operator, Stmt};
 use ruff_text_size::{Ranged, TextRange};
 
 use ruff_macros::{ViolationMetadata, derive_message_formats};
@@ -10,7 +10,8 @@ use crate::AlwaysFixableViolation;
 use crate::checkers::ast::Checker;
 
 /// ## What it does
-/// Checks for code that swaps two variables using a temporary variable.
+/// Checks for code that swaps two or more variables using a temporary
+/// variable.
 ///
 /// ## Why is this bad?
 /// Variables can be swapped by using tuple unpacking instead of using a
@@ -35,110 +36,250 @@ use crate::checkers::ast::Checker;
 ///     assert x <= y
 /// ```
 ///
+/// This also applies to rotations through more than two variables:
+/// ```python
+/// temp = a
+/// a = b
+/// b = c
+/// c = temp
+/// ```
+///
+/// Use instead:
+/// ```python
+/// a, b, c = b, c, a
+/// ```
+///
+/// The same goes for the classic no-temporary-variable idioms, such as the
+/// XOR swap:
+/// ```python
+/// x = x ^ y
+/// y = x ^ y
+/// x = x ^ y
+/// ```
+///
+/// or the additive swap:
+/// ```python
+/// x = x + y
+/// y = x - y
+/// x = x - y
+/// ```
+///
 /// ## Fix safety
-/// The rule's fix is marked as safe, unless it contains comments. In this
-/// exception case, applying the quick fix would remove comments between the
-/// assignment statements.
+/// The rule's fix is marked as safe, unless it contains comments, in which
+/// case applying the quick fix would remove comments between the assignment
+/// statements. The fix for the additive swap is always marked as unsafe,
+/// since `+`/`-` aren't exact inverses for floats, and the rule doesn't
+/// infer the operands' types.
 #[derive(ViolationMetadata)]
 #[violation_metadata(preview_since = "0.14.11")]
-pub(crate) struct SwapWithTemporaryVariable<'a> {
-    first_var: &'a Name,
-    second_var: &'a Name,
+pub(crate) struct SwapWithTemporaryVariable {
+    vars: Vec<String>,
 }
 
-impl AlwaysFixableViolation for SwapWithTemporaryVariable<'_> {
+impl AlwaysFixableViolation for SwapWithTemporaryVariable {
     #[derive_message_formats]
     fn message(&self) -> String {
-        let SwapWithTemporaryVariable {
-            first_var,
-            second_var,
-        } = self;
-        format!("Consider swapping `{first_var}` and `{second_var}` by using tuple unpacking")
+        format!(
+            "Consider swapping {} by using tuple unpacking",
+            join_with_and(&self.vars)
+        )
     }
 
     fn fix_title(&self) -> String {
-        let SwapWithTemporaryVariable {
-            first_var,
-            second_var,
-        } = self;
-        format!("Use `{first_var}, {second_var} = {second_var}, {first_var}` instead")
+        format!(
+            "Use `{} = {}` instead",
+            self.vars.join(", "),
+            rotated(&self.vars).join(", ")
+        )
     }
 }
 
+/// Joins `vars` into a human-readable, backtick-quoted, comma-separated list
+/// ending in "and", e.g. `` `a`, `b`, and `c` ``.
+fn join_with_and(vars: &[String]) -> String {
+    match vars {
+        [] => String::new(),
+        [a] => format!("`{a}`"),
+        [a, b] => format!("`{a}` and `{b}`"),
+        [rest @ .., last] => {
+            let rest = rest
+                .iter()
+                .map(|var| format!("`{var}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{rest}, and `{last}`")
+        }
+    }
+}
+
+/// Returns `vars` rotated one position to the left, i.e. the right-hand side
+/// of the tuple-unpacking fix for the rotation `vars[0] = vars[1] = ... =
+/// vars[n - 1] = vars[0]`.
+fn rotated(vars: &[String]) -> Vec<String> {
+    let mut rotated = vars.to_vec();
+    rotated.rotate_left(1);
+    rotated
+}
+
 pub(crate) fn swap_with_temporary_variable(checker: &Checker, stmts: &[Stmt]) {
-    for stmt_sequence in stmts
+    detect_rotation(checker, stmts);
+    detect_binop_swap(checker, stmts);
+}
+
+fn detect_rotation(checker: &Checker, stmts: &[Stmt]) {
+    let assignments = stmts
         .iter()
         .map(VarToVarAssignment::from_stmt)
-        .tuple_windows()
-    {
-        // if unwrapping fails, one of the statements hasn't been a var to var assignment
-        let (Some(stmt_a), Some(stmt_b), Some(stmt_c)) = stmt_sequence else {
+        .collect::<Vec<_>>();
+
+    let mut index = 0;
+    while index < assignments.len() {
+        // The maximal run of consecutive `VarToVarAssignment`s starting here.
+        let run_len = assignments[index..]
+            .iter()
+            .position(Option::is_none)
+            .unwrap_or(assignments.len() - index);
+
+        if run_len < 3 {
+            index += run_len.max(1);
             continue;
-        };
+        }
 
-        // Detect patterns like:
-        // temp = x
-        // x = y
-        // y = temp
-        if stmt_a.value == stmt_b.target
-            && stmt_b.value == stmt_c.target
-            && stmt_a.target == stmt_c.value
-        {
-            let diagnostic = SwapWithTemporaryVariable {
-                first_var: &stmt_b.target,
-                second_var: &stmt_c.target,
-            };
-            let edit_range = TextRange::new(stmt_a.range.start(), stmt_c.range.end());
-            let edit = Edit::range_replacement(
-                format!(
-                    "{0}, {1} = {1}, {0}",
-                    &diagnostic.first_var, &diagnostic.second_var
-                ),
-                edit_range,
-            );
-            let mut diagnostic_guard = checker.report_diagnostic(diagnostic, edit_range);
-
-            // Get the variable binding of the temporary variable that's used to swap the variables,
-            // e.g. in the example above, this would be the `temp` variable.
-            let temporary_variable_binding = checker
-                .semantic()
-                .bindings
-                .iter()
-                .find(|binding| stmt_a.range.contains_range(binding.range))
-                .unwrap();
-
-            // If the temporary variable is global (e.g., `global SWAP_VAR`) or nonlocal (e.g., `nonlocal SWAP_VAR`),
-            // then it is intended to also be used elsewhere outside our scope and hence can not be easily removed
-            // by applying a quick fix.
-            if temporary_variable_binding.is_global() || temporary_variable_binding.is_nonlocal() {
-                continue;
-            }
+        let run = assignments[index..index + run_len]
+            .iter()
+            .map(|assignment| assignment.as_ref().unwrap())
+            .collect::<Vec<_>>();
 
-            // In case there's any later reference to the temporary variable, the quick fix would also not be applicable
-            // because it would remove the temporary variable declaration, but not its use later in the code.
-            if temporary_variable_binding
-                .references()
-                .map(|reference| checker.semantic().reference(reference))
-                .any(|other_reference| edit_range.end() < other_reference.start())
-            {
-                continue;
-            }
+        // Try the longest possible rotation first, shrinking until something
+        // matches. For a run of two back-to-back swaps, `try_rotation` rejects
+        // the whole run (the scratch variable shows up among the chained
+        // variables), so the first swap then matches on its own and the second
+        // is picked up from the next index.
+        let matched_len = (3..=run_len)
+            .rev()
+            .find(|&len| try_rotation(checker, &run[..len]));
+
+        index += matched_len.unwrap_or(1);
+    }
+}
 
-            // The quick fix would remove comments, hence it's unsafe if there are any comments in the relevant code part.
-            let applicability = if checker.comment_ranges().intersects(edit.range()) {
-                Applicability::Unsafe
-            } else {
-                Applicability::Safe
-            };
-            diagnostic_guard.set_fix(Fix::applicable_edit(edit, applicability));
+/// Attempts to match `window` (a run of consecutive `VarToVarAssignment`s)
+/// against the rotation pattern:
+///
+/// ```python
+/// temp = x0
+/// x0 = x1
+/// x1 = x2
+/// ...
+/// x_{k-1} = x_k
+/// x_k = temp
+/// ```
+///
+/// which collapses to `x0, x1, ..., x_k = x1, x2, ..., x0`. A swap of two
+/// variables is simply a rotation of length 2 (`k == 1`). On a match, reports
+/// the diagnostic (with a fix, unless unsafe) and returns `true`.
+fn try_rotation(checker: &Checker, window: &[&VarToVarAssignment]) -> bool {
+    let Some((first, chain)) = window.split_first() else {
+        return false;
+    };
+    let Some((closing, links)) = chain.split_last() else {
+        return false;
+    };
+
+    // The scratch variable must be a plain name: only a `Name` has a
+    // `Binding` we can inspect below for `global`/`nonlocal` declarations and
+    // later references, both of which determine whether it's safe to delete.
+    // (The request to generalize to attribute/subscript targets applies to
+    // the swapped variables themselves, not to this temporary.)
+    let Expr::Name(_) = &first.target else {
+        return false;
+    };
+
+    let temp = &first.target;
+    let mut vars = vec![&first.value];
+    for link in links {
+        if ComparableExpr::from(&link.target) != ComparableExpr::from(*vars.last().unwrap()) {
+            return false;
         }
+        vars.push(&link.value);
+    }
+    if ComparableExpr::from(&closing.target) != ComparableExpr::from(*vars.last().unwrap())
+        || ComparableExpr::from(&closing.value) != ComparableExpr::from(temp)
+    {
+        return false;
     }
+
+    // `temp` must not also appear among the chained variables: if it did,
+    // two independent swaps that happen to reuse the same scratch name could
+    // be mistaken for one rotation through the scratch variable, which isn't
+    // equivalent (the scratch variable would end up holding a different
+    // value than the original code left it with).
+    if vars
+        .iter()
+        .any(|var| ComparableExpr::from(*var) == ComparableExpr::from(temp))
+    {
+        return false;
+    }
+
+    let var_names = vars
+        .iter()
+        .map(|var| checker.locator().slice(var.range()).to_string())
+        .collect::<Vec<_>>();
+    let diagnostic = SwapWithTemporaryVariable {
+        vars: var_names.clone(),
+    };
+    let edit_range = TextRange::new(first.range.start(), closing.range.end());
+    let edit = Edit::range_replacement(
+        format!(
+            "{} = {}",
+            var_names.join(", "),
+            rotated(&var_names).join(", ")
+        ),
+        edit_range,
+    );
+    let mut diagnostic_guard = checker.report_diagnostic(diagnostic, edit_range);
+
+    // The temporary variable used to swap the values, e.g. in the example
+    // above, this would be the `temp` variable.
+    let temporary_variable_binding = checker
+        .semantic()
+        .bindings
+        .iter()
+        .find(|binding| first.range.contains_range(binding.range))
+        .unwrap();
+
+    // If the temporary variable is global (e.g., `global SWAP_VAR`) or nonlocal (e.g., `nonlocal SWAP_VAR`),
+    // then it is intended to also be used elsewhere outside our scope and hence can not be easily removed
+    // by applying a quick fix.
+    if temporary_variable_binding.is_global() || temporary_variable_binding.is_nonlocal() {
+        return true;
+    }
+
+    // In case there's any later reference to the temporary variable, the quick fix would also not be applicable
+    // because it would remove the temporary variable declaration, but not its use later in the code.
+    if temporary_variable_binding
+        .references()
+        .map(|reference| checker.semantic().reference(reference))
+        .any(|other_reference| edit_range.end() < other_reference.start())
+    {
+        return true;
+    }
+
+    // The quick fix would remove comments, hence it's unsafe if there are any comments in the relevant code part.
+    let applicability = if checker.comment_ranges().intersects(edit.range()) {
+        Applicability::Unsafe
+    } else {
+        Applicability::Safe
+    };
+    diagnostic_guard.set_fix(Fix::applicable_edit(edit, applicability));
+
+    true
 }
 
-#[derive(Eq, PartialEq, Debug, Clone)]
+#[derive(Debug, Clone)]
 struct VarToVarAssignment {
-    target: Name,
-    value: Name,
+    target: Expr,
+    value: Expr,
     range: TextRange,
 }
 
@@ -151,7 +292,7 @@ impl VarToVarAssignment {
                     return None;
                 };
 
-                (target_variable, &stmt_assign.value)
+                (target_variable, &*stmt_assign.value)
             }
             Stmt::AnnAssign(stmt_ann_assign) => {
                 // only assignments that actually assign a value are relevant here
@@ -159,20 +300,18 @@ impl VarToVarAssignment {
                     return None;
                 };
 
-                (&*stmt_ann_assign.target, value)
+                (&*stmt_ann_assign.target, &**value)
             }
             // Stmt::AugAssign is not relevant because it modifies the content
             // of a variable based on its existing value, so it can't swap variables
             _ => return None,
         };
 
-        // assignment value is more complex than just a simple variable, skip such cases.
-        if let (Some(target_expr), Some(value_expr)) =
-            (target.clone().name_expr(), value.clone().name_expr())
-        {
+        // assignment value is more complex than just a simple reference, skip such cases.
+        if is_simple_reference(target) && is_simple_reference(value) {
             Some(Self {
-                target: target_expr.id,
-                value: value_expr.id,
+                target: target.clone(),
+                value: value.clone(),
                 range: stmt.range(),
             })
         } else {
@@ -180,3 +319,201 @@ impl VarToVarAssignment {
         }
     }
 }
+
+/// Returns `true` if `expr` is a plain name, or an attribute/subscript chain
+/// rooted in one (e.g. `self.x`, `self.data[0]`), with any subscript index
+/// limited to a name or a literal constant.
+///
+/// This excludes anything that could have a side effect (e.g. a call, such
+/// as in `d[f()]`), since the swap fix re-evaluates the target and value
+/// expressions, and re-evaluating a side-effecting expression twice would
+/// change its behavior.
+fn is_simple_reference(expr: &Expr) -> bool {
+    match expr {
+        Expr::Name(_) => true,
+        Expr::Attribute(ExprAttribute { value, .. }) => is_simple_reference(value),
+        // A literal (e.g. `0`, `"key"`) is only allowed as the subscript
+        // *index* here, never as the reference itself: `temp = 5` isn't a
+        // swap target/value, so the top-level match arms above and below
+        // intentionally don't accept `Expr::NumberLiteral` and friends.
+        Expr::Subscript(ExprSubscript { value, slice, .. }) => {
+            is_simple_reference(value)
+                && (matches!(slice.as_ref(), Expr::Name(_)) || slice.is_literal_expr())
+        }
+        _ => false,
+    }
+}
+
+/// Detects the classic no-temporary-variable swap idioms: the XOR swap
+/// (`x = x ^ y; y = x ^ y; x = x ^ y`) and the additive swap
+/// (`x = x + y; y = x - y; x = x - y`), including their augmented-assignment
+/// spellings (e.g. `x ^= y`).
+fn detect_binop_swap(checker: &Checker, stmts: &[Stmt]) {
+    for window in stmts.windows(3) {
+        let [first, second, third] = window else {
+            continue;
+        };
+        let (Some(first), Some(second), Some(third)) = (
+            SimpleBinOpAssignment::from_stmt(first),
+            SimpleBinOpAssignment::from_stmt(second),
+            SimpleBinOpAssignment::from_stmt(third),
+        ) else {
+            continue;
+        };
+
+        let Some(applicability) = match_binop_swap(&first, &second, &third) else {
+            continue;
+        };
+
+        let first_var = first.target.to_string();
+        let second_var = second.target.to_string();
+        let diagnostic = SwapWithTemporaryVariable {
+            vars: vec![first_var.clone(), second_var.clone()],
+        };
+        let edit_range = TextRange::new(first.range.start(), third.range.end());
+        let edit = Edit::range_replacement(
+            format!("{first_var}, {second_var} = {second_var}, {first_var}"),
+            edit_range,
+        );
+
+        // The quick fix would remove comments, hence it's unsafe if there are any comments in the relevant code part.
+        let applicability = if checker.comment_ranges().intersects(edit.range()) {
+            Applicability::Unsafe
+        } else {
+            applicability
+        };
+
+        checker
+            .report_diagnostic(diagnostic, edit_range)
+            .set_fix(Fix::applicable_edit(edit, applicability));
+    }
+}
+
+/// If `first`, `second`, and `third` together form a swap of two variables
+/// `x` and `y` via a reversible binary operation, returns the fix
+/// applicability for the swap; otherwise returns `None`.
+fn match_binop_swap(
+    first: &SimpleBinOpAssignment,
+    second: &SimpleBinOpAssignment,
+    third: &SimpleBinOpAssignment,
+) -> Option<Applicability> {
+    let x = &first.target;
+    let y = &second.target;
+    if x == y || third.target != *x {
+        return None;
+    }
+
+    // The XOR swap is commutative and self-inverse for any type that
+    // supports `^` (e.g. `int`, `bool`, `set`), so the operand order doesn't
+    // matter and the fix is always safe.
+    let is_xy_pair =
+        |assignment: &SimpleBinOpAssignment| -> bool { operands_are(assignment, x, y) };
+    if first.op == BinOpKind::Xor
+        && second.op == BinOpKind::Xor
+        && third.op == BinOpKind::Xor
+        && is_xy_pair(first)
+        && is_xy_pair(second)
+        && is_xy_pair(third)
+    {
+        return Some(Applicability::Safe);
+    }
+
+    // The additive swap only works with this exact operand order: `x = x +
+    // y`, `y = x - y`, `x = x - y`. It's only exact for types where `+`/`-`
+    // are true inverses (e.g. `int`), which the rule doesn't verify, so the
+    // fix is marked as unsafe.
+    if first.op == BinOpKind::Add
+        && second.op == BinOpKind::Sub
+        && third.op == BinOpKind::Sub
+        && first.left == *x
+        && first.right == *y
+        && second.left == *x
+        && second.right == *y
+        && third.left == *x
+        && third.right == *y
+    {
+        return Some(Applicability::Unsafe);
+    }
+
+    None
+}
+
+/// Returns `true` if `assignment`'s operands are `x` and `y`, in either order.
+fn operands_are(assignment: &SimpleBinOpAssignment, x: &Name, y: &Name) -> bool {
+    (assignment.left == *x && assignment.right == *y)
+        || (assignment.left == *y && assignment.right == *x)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinOpKind {
+    Xor,
+    Add,
+    Sub,
+}
+
+impl TryFrom<Operator> for BinOpKind {
+    type Error = ();
+
+    fn try_from(op: Operator) -> Result<Self, Self::Error> {
+        match op {
+            Operator::BitXor => Ok(BinOpKind::Xor),
+            Operator::Add => Ok(BinOpKind::Add),
+            Operator::Sub => Ok(BinOpKind::Sub),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A statement of the form `target = left <op> right` (from `Stmt::Assign`),
+/// or its augmented-assignment equivalent `target <op>= right` (from
+/// `Stmt::AugAssign`, where `left` is just `target` again).
+#[derive(Debug, Clone)]
+struct SimpleBinOpAssignment {
+    target: Name,
+    op: BinOpKind,
+    left: Name,
+    right: Name,
+    range: TextRange,
+}
+
+impl SimpleBinOpAssignment {
+    fn from_stmt(stmt: &Stmt) -> Option<Self> {
+        match stmt {
+            Stmt::Assign(stmt_assign) => {
+                let [target] = stmt_assign.targets.as_slice() else {
+                    return None;
+                };
+                let target = target.clone().name_expr()?.id;
+
+                let Expr::BinOp(bin_op) = &*stmt_assign.value else {
+                    return None;
+                };
+                let op = BinOpKind::try_from(bin_op.op).ok()?;
+                let left = bin_op.left.clone().name_expr()?.id;
+                let right = bin_op.right.clone().name_expr()?.id;
+
+                Some(Self {
+                    target,
+                    op,
+                    left,
+                    right,
+                    range: stmt.range(),
+                })
+            }
+            Stmt::AugAssign(stmt_aug_assign) => {
+                let target = stmt_aug_assign.target.clone().name_expr()?.id;
+                let op = BinOpKind::try_from(stmt_aug_assign.op).ok()?;
+                let right = stmt_aug_assign.value.clone().name_expr()?.id;
+
+                Some(Self {
+                    left: target.clone(),
+                    target,
+                    op,
+                    right,
+                    range: stmt.range(),
+                })
+            }
+            _ => None,
+        }
+    }
+}